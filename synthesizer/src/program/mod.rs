@@ -611,6 +611,13 @@ impl<N: Network> TypeName for Program<N> {
     }
 }
 
+impl<N: Network> core::hash::Hash for Program<N> {
+    /// Hashes the program by its program ID, which is consistent with equality.
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -653,6 +660,26 @@ mapping message:
         Ok(())
     }
 
+    #[test]
+    fn test_program_hash() -> Result<()> {
+        // Initialize the credits program.
+        let program = Program::<CurrentNetwork>::credits()?;
+
+        // Insert duplicate programs into a set.
+        let mut programs = std::collections::HashSet::new();
+        programs.insert(program.clone());
+        programs.insert(program.clone());
+        // Ensure the duplicate was deduplicated.
+        assert_eq!(programs.len(), 1);
+
+        // Insert a distinct program into the set.
+        programs.insert(Program::<CurrentNetwork>::new(ProgramID::from_str("unknown.aleo")?)?);
+        // Ensure the distinct program was added.
+        assert_eq!(programs.len(), 2);
+
+        Ok(())
+    }
+
     #[test]
     fn test_program_struct() -> Result<()> {
         // Create a new struct.